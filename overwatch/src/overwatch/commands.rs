@@ -0,0 +1,43 @@
+//! Commands accepted by the `Overwatch` core dispatch loop, sent by service handles through an
+//! [`OverwatchHandle`](super::handle::OverwatchHandle).
+
+use tokio::sync::oneshot;
+
+use crate::services::relay::RelayResult;
+use crate::services::ServiceId;
+
+/// One-shot reply slot a command carries so the dispatch loop can hand its result straight back
+/// to the caller awaiting it.
+pub struct ReplyChannel<T>(pub oneshot::Sender<T>);
+
+/// A request to connect to (or subscribe to the broadcast of) the service identified by
+/// `service_id`, answered via `reply_channel`.
+pub struct RelayCommand {
+    pub service_id: ServiceId,
+    pub reply_channel: ReplyChannel<RelayResult>,
+}
+
+/// A [`RelayCommand`] parked until `service_id` registers, tagged with a `token` unique to this
+/// request so a later [`OverwatchCommand::CancelRelayRendezvous`] can evict exactly this one
+/// without disturbing any other caller parked for the same service.
+pub struct RendezvousCommand {
+    pub service_id: ServiceId,
+    pub token: u64,
+    pub reply_channel: ReplyChannel<RelayResult>,
+}
+
+/// Commands accepted by the core dispatch loop ([`crate::overwatch::core::OverwatchCore::run`]).
+pub enum OverwatchCommand {
+    /// Connect to a service's plain relay; fails immediately if the service hasn't registered.
+    Relay(RelayCommand),
+    /// Subscribe to a service's broadcast relay; fails immediately if the service hasn't
+    /// registered.
+    SubscribeBroadcast(RelayCommand),
+    /// Like [`Self::Relay`], but park the request if the service hasn't registered yet instead
+    /// of failing, draining it as soon as the service does register.
+    RelayRendezvous(RendezvousCommand),
+    /// Evict a previously parked [`Self::RelayRendezvous`] request matching `service_id` and
+    /// `token`, e.g. after its caller gave up waiting. Other requests parked for the same
+    /// service are left untouched.
+    CancelRelayRendezvous { service_id: ServiceId, token: u64 },
+}
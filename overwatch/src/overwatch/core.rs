@@ -0,0 +1,172 @@
+//! Core `Overwatch` dispatch loop: owns every registered service's relay handle and answers
+//! [`OverwatchCommand`]s from [`OverwatchHandle`](super::handle::OverwatchHandle)s as services
+//! connect to each other.
+
+use std::any::Any;
+use std::collections::HashMap;
+
+use tokio::sync::mpsc::Receiver;
+
+use crate::services::relay::{AnyMessage, RelayError, RelayResult};
+use crate::services::ServiceId;
+
+use super::commands::{OverwatchCommand, RelayCommand, RendezvousCommand};
+
+/// A registered relay handle, type-erased so services with different message types can share
+/// one registry. Cloning hands back a fresh handle to the same underlying relay: both
+/// [`OutboundRelay`](crate::services::relay::OutboundRelay) and
+/// [`BroadcastOutbound`](crate::services::relay::BroadcastOutbound) clone cheaply regardless of
+/// their message type.
+trait ErasedRelay: Send {
+    fn clone_boxed(&self) -> AnyMessage;
+}
+
+impl<T: Any + Clone + Send + 'static> ErasedRelay for T {
+    fn clone_boxed(&self) -> AnyMessage {
+        Box::new(self.clone())
+    }
+}
+
+/// Requests parked by [`OverwatchCommand::RelayRendezvous`] for a service that hasn't
+/// registered yet, drained in order as soon as it does.
+type PendingRendezvous = HashMap<ServiceId, Vec<RendezvousCommand>>;
+
+/// Owns the registry of every service's relay handle and answers connection requests from
+/// [`OverwatchHandle`](super::handle::OverwatchHandle)s as they arrive.
+#[derive(Default)]
+pub struct OverwatchCore {
+    relays: HashMap<ServiceId, Box<dyn ErasedRelay>>,
+    broadcasts: HashMap<ServiceId, Box<dyn ErasedRelay>>,
+    pending_rendezvous: PendingRendezvous,
+}
+
+impl OverwatchCore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `service_id`'s plain relay handle, draining any
+    /// [`OverwatchCommand::RelayRendezvous`] requests parked for it.
+    pub fn register_relay<M: Any + Clone + Send + 'static>(&mut self, service_id: ServiceId, outbound: M) {
+        if let Some(parked) = self.pending_rendezvous.remove(&service_id) {
+            for command in parked {
+                let _ = command
+                    .reply_channel
+                    .0
+                    .send(Ok(Box::new(outbound.clone()) as AnyMessage));
+            }
+        }
+        self.relays.insert(service_id, Box::new(outbound));
+    }
+
+    /// Register `service_id`'s broadcast relay handle.
+    pub fn register_broadcast<M: Any + Clone + Send + 'static>(&mut self, service_id: ServiceId, outbound: M) {
+        self.broadcasts.insert(service_id, Box::new(outbound));
+    }
+
+    /// Drive the dispatch loop until `commands` closes, answering each [`OverwatchCommand`]
+    /// from the current registry.
+    pub async fn run(mut self, mut commands: Receiver<OverwatchCommand>) {
+        while let Some(command) = commands.recv().await {
+            self.handle_command(command);
+        }
+    }
+
+    fn handle_command(&mut self, command: OverwatchCommand) {
+        match command {
+            OverwatchCommand::Relay(RelayCommand {
+                service_id,
+                reply_channel,
+            }) => {
+                let _ = reply_channel.0.send(Self::lookup(&self.relays, service_id));
+            }
+            OverwatchCommand::SubscribeBroadcast(RelayCommand {
+                service_id,
+                reply_channel,
+            }) => {
+                let _ = reply_channel
+                    .0
+                    .send(Self::lookup(&self.broadcasts, service_id));
+            }
+            OverwatchCommand::RelayRendezvous(command) => {
+                if self.relays.contains_key(&command.service_id) {
+                    let response = Self::lookup(&self.relays, command.service_id);
+                    let _ = command.reply_channel.0.send(response);
+                } else {
+                    self.pending_rendezvous
+                        .entry(command.service_id)
+                        .or_default()
+                        .push(command);
+                }
+            }
+            OverwatchCommand::CancelRelayRendezvous { service_id, token } => {
+                // The caller already gave up locally; evict only its own entry so any other
+                // request parked for the same service is left waiting undisturbed.
+                if let Some(parked) = self.pending_rendezvous.get_mut(&service_id) {
+                    parked.retain(|command| command.token != token);
+                }
+            }
+        }
+    }
+
+    fn lookup(registry: &HashMap<ServiceId, Box<dyn ErasedRelay>>, service_id: ServiceId) -> RelayResult {
+        registry
+            .get(&service_id)
+            .map(|relay| relay.clone_boxed())
+            .ok_or(RelayError::Unavailable { service_id })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::overwatch::commands::ReplyChannel;
+    use tokio::sync::oneshot;
+
+    fn rendezvous(service_id: ServiceId, token: u64) -> (RendezvousCommand, oneshot::Receiver<RelayResult>) {
+        let (reply, receiver) = oneshot::channel();
+        (
+            RendezvousCommand {
+                service_id,
+                token,
+                reply_channel: ReplyChannel(reply),
+            },
+            receiver,
+        )
+    }
+
+    #[tokio::test]
+    async fn cancelling_one_rendezvous_request_leaves_others_parked_for_the_same_service() {
+        let mut core = OverwatchCore::new();
+        let (first, first_receiver) = rendezvous("target", 1);
+        let (second, second_receiver) = rendezvous("target", 2);
+        core.handle_command(OverwatchCommand::RelayRendezvous(first));
+        core.handle_command(OverwatchCommand::RelayRendezvous(second));
+
+        core.handle_command(OverwatchCommand::CancelRelayRendezvous {
+            service_id: "target",
+            token: 1,
+        });
+
+        // The cancelled caller's reply channel is dropped, never fulfilled.
+        assert!(first_receiver.await.is_err());
+
+        // The other caller, which never cancelled, is still parked and gets drained normally.
+        core.register_relay("target", 42u32);
+        assert!(matches!(second_receiver.await, Ok(Ok(_))));
+    }
+
+    #[tokio::test]
+    async fn register_relay_drains_every_parked_rendezvous_request() {
+        let mut core = OverwatchCore::new();
+        let (first, first_receiver) = rendezvous("target", 1);
+        let (second, second_receiver) = rendezvous("target", 2);
+        core.handle_command(OverwatchCommand::RelayRendezvous(first));
+        core.handle_command(OverwatchCommand::RelayRendezvous(second));
+
+        core.register_relay("target", 42u32);
+
+        assert!(matches!(first_receiver.await, Ok(Ok(_))));
+        assert!(matches!(second_receiver.await, Ok(Ok(_))));
+    }
+}
@@ -0,0 +1,25 @@
+//! Handle used by services to talk to the `Overwatch` core dispatch loop.
+
+use tokio::sync::mpsc::Sender;
+
+use super::commands::OverwatchCommand;
+
+/// Cloneable handle to the core dispatch loop, held by every
+/// [`Relay`](crate::services::relay::Relay) and
+/// [`BroadcastRelay`](crate::services::relay::BroadcastRelay) to request relays from the core.
+#[derive(Clone)]
+pub struct OverwatchHandle {
+    sender: Sender<OverwatchCommand>,
+}
+
+impl OverwatchHandle {
+    pub fn new(sender: Sender<OverwatchCommand>) -> Self {
+        Self { sender }
+    }
+
+    /// Send `command` to the core dispatch loop, dropping it silently if the loop has already
+    /// shut down.
+    pub async fn send(&self, command: OverwatchCommand) {
+        let _ = self.sender.send(command).await;
+    }
+}
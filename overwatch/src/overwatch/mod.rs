@@ -0,0 +1,11 @@
+//! The `Overwatch` core: a central dispatch loop that owns every service's relay handle and
+//! answers connection requests from [`crate::services::relay::Relay`] and
+//! [`crate::services::relay::BroadcastRelay`].
+
+pub mod commands;
+pub mod core;
+pub mod handle;
+
+pub use commands::OverwatchCommand;
+pub use core::OverwatchCore;
+pub use handle::OverwatchHandle;
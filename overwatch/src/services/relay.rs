@@ -1,16 +1,33 @@
 // std
 use std::any::Any;
 use std::fmt::Debug;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 // crates
+use dashmap::DashMap;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
+use tokio::sync::broadcast;
 use tokio::sync::mpsc::{channel, Receiver, Sender};
 use tokio::sync::oneshot;
 use tracing::{error, instrument};
 // internal
-use crate::overwatch::commands::{OverwatchCommand, RelayCommand, ReplyChannel};
+use crate::overwatch::commands::{OverwatchCommand, RelayCommand, RendezvousCommand, ReplyChannel};
 use crate::overwatch::handle::OverwatchHandle;
 use crate::services::{ServiceCore, ServiceId};
 
+mod transport;
+pub use transport::{BincodeCodec, Codec, Connection, Listener, TcpListener, TcpTransport, Transport};
+#[cfg(unix)]
+pub use transport::{UnixListener, UnixTransport};
+#[cfg(windows)]
+pub use transport::{NamedPipeListener, NamedPipeTransport};
+
+/// Buffer size used for the local half of a [`Relay::connect_remote`] bridge.
+const REMOTE_RELAY_BUFFER_SIZE: usize = 16;
+
 #[derive(Error, Debug)]
 pub enum RelayError {
     #[error("error requesting relay to {to} service")]
@@ -30,6 +47,32 @@ pub enum RelayError {
     },
     #[error("receiver failed due to {0:?}")]
     Receiver(Box<dyn Debug + Send + Sync>),
+    #[error("timed out waiting for a reply")]
+    Timeout,
+    #[error("broadcast subscriber lagged behind and missed {skipped} messages")]
+    Lagged { skipped: u64 },
+    #[error("relay queue is full")]
+    Full,
+}
+
+/// Backpressure policy applied when an [`OutboundRelay`]'s queue is full.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum SendPolicy {
+    /// Await until the queue has capacity. The default, and the historical behaviour of
+    /// [`OutboundRelay::send`].
+    #[default]
+    Block,
+    /// Fail immediately with [`RelayError::Full`] instead of waiting.
+    TrySend,
+    /// Wait up to the given duration, then fail with [`RelayError::Timeout`].
+    Timeout(Duration),
+    /// Shed load instead of queueing indefinitely: fail immediately with [`RelayError::Full`]
+    /// when the queue is full, dropping the incoming message rather than waiting.
+    ///
+    /// This rejects the *newest* message, not the oldest queued one -- tokio's bounded mpsc
+    /// channel gives the sender no way to evict an already-queued item, so true "drop oldest,
+    /// let the freshest through" load-shedding isn't possible over this channel.
+    ShedNewest,
 }
 
 /// Message wrapper type
@@ -61,16 +104,73 @@ impl<M> Clone for RelayState<M> {
     }
 }
 
+/// Point-in-time view of a [`RelayStats`], cheap to clone and serialize, keyed downstream by
+/// [`ServiceId`] the same way `MetricsBackend::update(service_id, data)` consumes other data.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelayStatsSnapshot {
+    pub messages_sent: u64,
+    pub messages_received: u64,
+    pub send_errors: u64,
+    pub queue_depth: usize,
+    pub last_activity: Option<SystemTime>,
+}
+
+/// Live counters tracking activity on a relay, shared between its [`InboundRelay`] and
+/// [`OutboundRelay`] halves so both sides observe the same numbers.
+#[derive(Debug, Default)]
+pub struct RelayStats {
+    messages_sent: AtomicU64,
+    messages_received: AtomicU64,
+    send_errors: AtomicU64,
+    last_activity_millis: AtomicU64,
+}
+
+impl RelayStats {
+    fn record_send(&self) {
+        self.messages_sent.fetch_add(1, Ordering::Relaxed);
+        self.touch();
+    }
+
+    fn record_send_error(&self) {
+        self.send_errors.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_recv(&self) {
+        self.messages_received.fetch_add(1, Ordering::Relaxed);
+        self.touch();
+    }
+
+    fn touch(&self) {
+        if let Ok(elapsed) = SystemTime::now().duration_since(UNIX_EPOCH) {
+            self.last_activity_millis
+                .store(elapsed.as_millis() as u64, Ordering::Relaxed);
+        }
+    }
+
+    fn snapshot(&self, queue_depth: usize) -> RelayStatsSnapshot {
+        let last_activity_millis = self.last_activity_millis.load(Ordering::Relaxed);
+        RelayStatsSnapshot {
+            messages_sent: self.messages_sent.load(Ordering::Relaxed),
+            messages_received: self.messages_received.load(Ordering::Relaxed),
+            send_errors: self.send_errors.load(Ordering::Relaxed),
+            queue_depth,
+            last_activity: (last_activity_millis > 0)
+                .then(|| UNIX_EPOCH + Duration::from_millis(last_activity_millis)),
+        }
+    }
+}
+
 /// Channel receiver of a relay connection
 pub struct InboundRelay<M> {
     receiver: Receiver<M>,
-    _stats: (), // placeholder
+    stats: Arc<RelayStats>,
 }
 
 /// Channel sender of a relay connection
 pub struct OutboundRelay<M> {
     sender: Sender<M>,
-    _stats: (), // placeholder
+    stats: Arc<RelayStats>,
+    policy: SendPolicy,
 }
 
 pub struct Relay<S: ServiceCore> {
@@ -91,29 +191,114 @@ impl<S: ServiceCore> Clone for Relay<S> {
 /// Relay channel builder
 pub fn relay<M>(buffer_size: usize) -> (InboundRelay<M>, OutboundRelay<M>) {
     let (sender, receiver) = channel(buffer_size);
+    let stats = Arc::new(RelayStats::default());
     (
         InboundRelay {
             receiver,
-            _stats: (),
+            stats: Arc::clone(&stats),
+        },
+        OutboundRelay {
+            sender,
+            stats,
+            policy: SendPolicy::default(),
         },
-        OutboundRelay { sender, _stats: () },
     )
 }
 
 impl<M> InboundRelay<M> {
     /// Receive a message from the relay connections
     pub async fn recv(&mut self) -> Option<M> {
-        self.receiver.recv().await
+        let message = self.receiver.recv().await;
+        if message.is_some() {
+            self.stats.record_recv();
+        }
+        message
+    }
+
+    /// Current activity counters for this relay, including the number of messages currently
+    /// queued and not yet received.
+    pub fn stats(&self) -> RelayStatsSnapshot {
+        self.stats.snapshot(self.receiver.len())
     }
 }
 
 impl<M> OutboundRelay<M> {
-    /// Send a message to the relay connection
+    /// Select the [`SendPolicy`] applied by future calls to [`Self::send`].
+    pub fn set_policy(&mut self, policy: SendPolicy) {
+        self.policy = policy;
+    }
+
+    /// Send a message to the relay connection, honouring this relay's [`SendPolicy`]
+    /// (blocking by default).
     pub async fn send(&mut self, message: M) -> Result<(), (RelayError, M)> {
-        self.sender
+        match self.policy {
+            SendPolicy::Block => self.send_blocking(message).await,
+            SendPolicy::TrySend | SendPolicy::ShedNewest => self.try_send(message),
+            SendPolicy::Timeout(duration) => self.send_timeout(message, duration).await,
+        }
+    }
+
+    async fn send_blocking(&mut self, message: M) -> Result<(), (RelayError, M)> {
+        let result = self
+            .sender
             .send(message)
             .await
-            .map_err(|e| (RelayError::Send, e.0))
+            .map_err(|e| (RelayError::Send, e.0));
+        match &result {
+            Ok(()) => self.stats.record_send(),
+            Err(_) => self.stats.record_send_error(),
+        }
+        result
+    }
+
+    /// Send without waiting for capacity, failing with [`RelayError::Full`] if the queue is
+    /// currently full rather than applying backpressure to the sender.
+    pub fn try_send(&mut self, message: M) -> Result<(), (RelayError, M)> {
+        match self.sender.try_send(message) {
+            Ok(()) => {
+                self.stats.record_send();
+                Ok(())
+            }
+            Err(tokio::sync::mpsc::error::TrySendError::Full(message)) => {
+                self.stats.record_send_error();
+                Err((RelayError::Full, message))
+            }
+            Err(tokio::sync::mpsc::error::TrySendError::Closed(message)) => {
+                self.stats.record_send_error();
+                Err((RelayError::Send, message))
+            }
+        }
+    }
+
+    /// Send, waiting up to `timeout` for capacity, failing with [`RelayError::Timeout`] if it
+    /// elapses first.
+    pub async fn send_timeout(
+        &mut self,
+        message: M,
+        timeout: Duration,
+    ) -> Result<(), (RelayError, M)> {
+        match tokio::time::timeout(timeout, self.sender.reserve()).await {
+            Ok(Ok(permit)) => {
+                permit.send(message);
+                self.stats.record_send();
+                Ok(())
+            }
+            Ok(Err(_closed)) => {
+                self.stats.record_send_error();
+                Err((RelayError::Send, message))
+            }
+            Err(_elapsed) => {
+                self.stats.record_send_error();
+                Err((RelayError::Timeout, message))
+            }
+        }
+    }
+
+    /// Current activity counters for this relay, including the number of messages currently
+    /// queued and not yet received.
+    pub fn stats(&self) -> RelayStatsSnapshot {
+        let queue_depth = self.sender.max_capacity() - self.sender.capacity();
+        self.stats.snapshot(queue_depth)
     }
 }
 
@@ -121,7 +306,8 @@ impl<M> Clone for OutboundRelay<M> {
     fn clone(&self) -> Self {
         Self {
             sender: self.sender.clone(),
-            _stats: (),
+            stats: Arc::clone(&self.stats),
+            policy: self.policy,
         }
     }
 }
@@ -164,6 +350,72 @@ impl<S: ServiceCore> Relay<S> {
         }
     }
 
+    /// Current activity counters for the underlying relay connection.
+    pub fn stats(&self) -> Result<RelayStatsSnapshot, RelayError> {
+        if let RelayState::Connected(outbound_relay) = &self.state {
+            Ok(outbound_relay.stats())
+        } else {
+            Err(RelayError::Disconnected)
+        }
+    }
+
+    /// Connect to a target service running in a different process or host, instead of one
+    /// managed by this `Overwatch` instance, by dialing `transport` and bridging messages
+    /// through it with `codec`.
+    ///
+    /// Messages sent afterwards via [`Self::send`] are encoded and framed onto the transport
+    /// by a background bridge task rather than delivered in-process.
+    #[instrument(skip(self, transport, codec), err(Debug))]
+    pub async fn connect_remote<T, Co>(
+        &mut self,
+        transport: T,
+        codec: Co,
+    ) -> Result<(), RelayError>
+    where
+        S::Message: Serialize + DeserializeOwned,
+        T: Transport,
+        Co: Codec<S::Message>,
+    {
+        if !matches!(self.state, RelayState::Disconnected) {
+            return Err(RelayError::AlreadyConnected);
+        }
+        let connection =
+            transport
+                .connect()
+                .await
+                .map_err(|_| RelayError::Unavailable {
+                    service_id: S::SERVICE_ID,
+                })?;
+        let (local_inbound, local_outbound) = relay(REMOTE_RELAY_BUFFER_SIZE);
+        tokio::spawn(transport::bridge_outbound(local_inbound, connection, codec));
+        self.state = RelayState::Connected(local_outbound);
+        Ok(())
+    }
+
+    /// Expose this relay to remote callers: accept connections off `listener` and bridge each
+    /// one, via `codec`, onto the in-process [`OutboundRelay`] this handle already holds.
+    ///
+    /// This is the counterpart of [`Self::connect_remote`] run on the process hosting the
+    /// target service: `connect_remote` dials out and forwards local sends onto the wire,
+    /// while `serve_remote` accepts those connections and re-injects the deframed messages
+    /// into the target service's own `InboundRelay`, completing the bridge at both ends.
+    /// Messages only travel from the caller to the target service; nothing is sent back.
+    /// Requires this relay to already be [`Self::connect`]ed in-process.
+    #[instrument(skip(self, listener, codec), err(Debug))]
+    pub async fn serve_remote<L, Co>(&self, listener: L, codec: Co) -> Result<(), RelayError>
+    where
+        S::Message: Serialize + DeserializeOwned,
+        L: Listener,
+        Co: Codec<S::Message>,
+    {
+        if let RelayState::Connected(outbound_relay) = &self.state {
+            tokio::spawn(transport::serve(listener, outbound_relay.clone(), codec));
+            Ok(())
+        } else {
+            Err(RelayError::Disconnected)
+        }
+    }
+
     async fn request_relay(&mut self, reply: oneshot::Sender<RelayResult>) {
         let relay_command = OverwatchCommand::Relay(RelayCommand {
             service_id: S::SERVICE_ID,
@@ -177,7 +429,13 @@ impl<S: ServiceCore> Relay<S> {
         &mut self,
         receiver: oneshot::Receiver<RelayResult>,
     ) -> Result<(), RelayError> {
-        let response = receiver.await;
+        self.apply_relay_response(receiver.await)
+    }
+
+    fn apply_relay_response(
+        &mut self,
+        response: Result<RelayResult, oneshot::error::RecvError>,
+    ) -> Result<(), RelayError> {
         match response {
             Ok(Ok(message)) => match message.downcast::<OutboundRelay<S::Message>>() {
                 Ok(channel) => {
@@ -193,4 +451,489 @@ impl<S: ServiceCore> Relay<S> {
             Err(e) => Err(RelayError::Receiver(Box::new(e))),
         }
     }
+
+    /// Connect to the target service, parking the request instead of failing immediately if
+    /// the service hasn't started yet.
+    ///
+    /// Overwatch queues the pending request keyed by [`ServiceId`]; as soon as that service
+    /// registers its relay, every queued requester is drained and handed the
+    /// [`OutboundRelay`]. If `timeout` elapses first, the parked entry is evicted and this
+    /// returns [`RelayError::Unavailable`], so callers don't have to busy-wait on startup
+    /// ordering between interdependent services.
+    #[instrument(skip(self), err(Debug))]
+    pub async fn connect_rendezvous(&mut self, timeout: Option<Duration>) -> Result<(), RelayError> {
+        if !matches!(self.state, RelayState::Disconnected) {
+            return Err(RelayError::AlreadyConnected);
+        }
+        let (reply, receiver) = oneshot::channel();
+        let token = next_request_id();
+        let relay_command = OverwatchCommand::RelayRendezvous(RendezvousCommand {
+            service_id: S::SERVICE_ID,
+            token,
+            reply_channel: ReplyChannel(reply),
+        });
+        self.overwatch_handle.send(relay_command).await;
+
+        let response = match timeout {
+            Some(duration) => match tokio::time::timeout(duration, receiver).await {
+                Ok(response) => response,
+                Err(_) => {
+                    self.overwatch_handle
+                        .send(OverwatchCommand::CancelRelayRendezvous {
+                            service_id: S::SERVICE_ID,
+                            token,
+                        })
+                        .await;
+                    return Err(RelayError::Unavailable {
+                        service_id: S::SERVICE_ID,
+                    });
+                }
+            },
+            None => receiver.await,
+        };
+        self.apply_relay_response(response)
+    }
+}
+
+/// Identifier correlating a request with its reply in a request/reply relay.
+type RequestId = u64;
+
+static NEXT_REQUEST_ID: AtomicU64 = AtomicU64::new(0);
+
+fn next_request_id() -> RequestId {
+    NEXT_REQUEST_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Map of in-flight requests waiting for a reply, keyed by [`RequestId`].
+type PendingRequests<R> = Arc<DashMap<RequestId, oneshot::Sender<R>>>;
+
+/// Wrapper tagging a value flowing through a request/reply relay with the [`RequestId`] it is
+/// correlated to, so the dispatcher can route a reply back to the caller that issued it.
+#[derive(Debug)]
+struct Envelope<T> {
+    request_id: RequestId,
+    payload: T,
+}
+
+/// Channel builder for a request/reply relay.
+///
+/// Unlike [`relay`], which only supports fire-and-forget messages, this pairs a request
+/// channel (caller -> service) with a reply channel (service -> caller) and a background
+/// dispatcher task that correlates each reply with the request it answers, so callers don't
+/// need to hand-roll a `oneshot` inside their message type to get an answer back.
+pub fn request_relay<M, R>(buffer_size: usize) -> (RequestInboundRelay<M, R>, RequestOutboundRelay<M, R>)
+where
+    M: Send + 'static,
+    R: Send + 'static,
+{
+    let (request_sender, request_receiver) = channel(buffer_size);
+    let (reply_sender, reply_receiver) = channel(buffer_size);
+    let pending: PendingRequests<R> = Arc::new(DashMap::new());
+
+    tokio::spawn(dispatch_replies(reply_receiver, Arc::clone(&pending)));
+
+    (
+        RequestInboundRelay {
+            receiver: request_receiver,
+            reply_sender,
+        },
+        RequestOutboundRelay {
+            sender: request_sender,
+            pending,
+        },
+    )
+}
+
+/// Drain replies off the reply channel and route each one back to the caller awaiting it,
+/// removing the entry from `pending` so it doesn't leak. A reply for a request whose caller
+/// already gave up (dropped the receiver, or timed out) is simply discarded. Once the reply
+/// channel closes, every request still pending is dropped so its caller sees
+/// [`RelayError::Disconnected`].
+async fn dispatch_replies<R>(mut replies: Receiver<Envelope<R>>, pending: PendingRequests<R>) {
+    while let Some(Envelope { request_id, payload }) = replies.recv().await {
+        if let Some((_, reply_sender)) = pending.remove(&request_id) {
+            let _ = reply_sender.send(payload);
+        }
+    }
+    pending.clear();
+}
+
+/// Receiving end of a request/reply relay, held by the service answering requests.
+pub struct RequestInboundRelay<M, R> {
+    receiver: Receiver<Envelope<M>>,
+    reply_sender: Sender<Envelope<R>>,
+}
+
+impl<M, R> RequestInboundRelay<M, R> {
+    /// Receive the next request, together with a [`Replier`] used to send back its answer.
+    pub async fn recv(&mut self) -> Option<(M, Replier<R>)> {
+        let Envelope { request_id, payload } = self.receiver.recv().await?;
+        Some((
+            payload,
+            Replier {
+                request_id,
+                sender: self.reply_sender.clone(),
+            },
+        ))
+    }
+}
+
+/// Handle used by a service to answer a single request received from a [`RequestInboundRelay`].
+pub struct Replier<R> {
+    request_id: RequestId,
+    sender: Sender<Envelope<R>>,
+}
+
+impl<R> Replier<R> {
+    /// Send the reply back to the caller awaiting it.
+    pub async fn reply(self, payload: R) -> Result<(), (RelayError, R)> {
+        self.sender
+            .send(Envelope {
+                request_id: self.request_id,
+                payload,
+            })
+            .await
+            .map_err(|e| (RelayError::Send, e.0.payload))
+    }
+}
+
+/// Sending end of a request/reply relay, held by the caller issuing requests.
+///
+/// Cloneable: every clone shares the same `pending` map, so several callers (or one caller
+/// pipelining several requests concurrently) can have independent requests in flight over the
+/// same relay at once, each tracked by its own [`RequestId`].
+pub struct RequestOutboundRelay<M, R> {
+    sender: Sender<Envelope<M>>,
+    pending: PendingRequests<R>,
+}
+
+impl<M, R> RequestOutboundRelay<M, R> {
+    /// Send `message` and await its correlated reply.
+    ///
+    /// Drops the pending entry and returns [`RelayError::Disconnected`] if the reply channel
+    /// closes (e.g. the service went away) before a response arrives.
+    #[instrument(skip(self, message), err(Debug))]
+    pub async fn request(&mut self, message: M) -> Result<R, RelayError>
+    where
+        M: Debug,
+    {
+        let (reply, receiver) = oneshot::channel();
+        let request_id = next_request_id();
+        self.pending.insert(request_id, reply);
+        if self
+            .sender
+            .send(Envelope {
+                request_id,
+                payload: message,
+            })
+            .await
+            .is_err()
+        {
+            self.pending.remove(&request_id);
+            return Err(RelayError::Disconnected);
+        }
+        receiver.await.map_err(|_| RelayError::Disconnected)
+    }
+
+    /// Like [`Self::request`], but evicts the pending entry and returns
+    /// [`RelayError::Timeout`] if no reply arrives within `timeout`.
+    #[instrument(skip(self, message), err(Debug))]
+    pub async fn request_timeout(&mut self, message: M, timeout: Duration) -> Result<R, RelayError>
+    where
+        M: Debug,
+    {
+        let (reply, receiver) = oneshot::channel();
+        let request_id = next_request_id();
+        self.pending.insert(request_id, reply);
+        if self
+            .sender
+            .send(Envelope {
+                request_id,
+                payload: message,
+            })
+            .await
+            .is_err()
+        {
+            self.pending.remove(&request_id);
+            return Err(RelayError::Disconnected);
+        }
+        match tokio::time::timeout(timeout, receiver).await {
+            Ok(Ok(reply)) => Ok(reply),
+            Ok(Err(_)) => {
+                self.pending.remove(&request_id);
+                Err(RelayError::Disconnected)
+            }
+            Err(_) => {
+                self.pending.remove(&request_id);
+                Err(RelayError::Timeout)
+            }
+        }
+    }
+}
+
+impl<M, R> Clone for RequestOutboundRelay<M, R> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+            pending: Arc::clone(&self.pending),
+        }
+    }
+}
+
+/// Broadcast relay channel builder.
+///
+/// Unlike [`relay`], which only supports a single consumer, this fans a single service's
+/// messages out to any number of subscribers via `tokio::sync::broadcast`: every message sent
+/// is cloned and delivered to every subscriber that is currently listening.
+pub fn broadcast_relay<M: Clone>(capacity: usize) -> (BroadcastOutbound<M>, BroadcastInbound<M>) {
+    let (sender, receiver) = broadcast::channel(capacity);
+    (BroadcastOutbound { sender }, BroadcastInbound { receiver })
+}
+
+/// Sending end of a broadcast relay, held by the service emitting events.
+pub struct BroadcastOutbound<M> {
+    sender: broadcast::Sender<M>,
+}
+
+impl<M: Clone> BroadcastOutbound<M> {
+    /// Publish `message` to all current subscribers.
+    pub fn send(&self, message: M) -> Result<usize, RelayError> {
+        self.sender
+            .send(message)
+            .map_err(|broadcast::error::SendError(_)| RelayError::Disconnected)
+    }
+
+    /// Create a fresh subscriber; it only observes messages sent after this call.
+    pub fn subscribe(&self) -> BroadcastInbound<M> {
+        BroadcastInbound {
+            receiver: self.sender.subscribe(),
+        }
+    }
+}
+
+impl<M> Clone for BroadcastOutbound<M> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+        }
+    }
+}
+
+/// Receiving end of a broadcast relay, obtained via [`BroadcastOutbound::subscribe`] or
+/// [`BroadcastRelay::subscribe`].
+pub struct BroadcastInbound<M> {
+    receiver: broadcast::Receiver<M>,
+}
+
+impl<M: Clone> BroadcastInbound<M> {
+    /// Receive the next broadcast message.
+    ///
+    /// Returns [`RelayError::Lagged`] if this subscriber fell behind and missed messages,
+    /// rather than silently dropping them: the caller can decide whether to keep consuming
+    /// from where the channel now stands or treat it as an error.
+    pub async fn recv(&mut self) -> Result<M, RelayError> {
+        self.receiver.recv().await.map_err(|error| match error {
+            broadcast::error::RecvError::Closed => RelayError::Disconnected,
+            broadcast::error::RecvError::Lagged(skipped) => RelayError::Lagged { skipped },
+        })
+    }
+}
+
+enum BroadcastRelayState<M> {
+    Disconnected,
+    Connected(BroadcastOutbound<M>),
+}
+
+impl<M> Clone for BroadcastRelayState<M> {
+    fn clone(&self) -> Self {
+        match self {
+            BroadcastRelayState::Disconnected => BroadcastRelayState::Disconnected,
+            BroadcastRelayState::Connected(outbound) => {
+                BroadcastRelayState::Connected(outbound.clone())
+            }
+        }
+    }
+}
+
+/// Handle used to subscribe to a service's broadcast relay, mirroring [`Relay`] but for the
+/// fan-out case: `connect` asks Overwatch for the service's [`BroadcastOutbound`] via
+/// [`OverwatchCommand::SubscribeBroadcast`], and each call to [`Self::subscribe`] hands back a
+/// fresh [`BroadcastInbound`] so several services can independently subscribe to the same one.
+///
+/// This is a separate type rather than a `Relay::subscribe()` method: `Relay<S>`'s connected
+/// state holds an mpsc [`OutboundRelay`], which has no `subscribe` of its own to expose, so
+/// fan-out needs its own state (a `broadcast::Sender`) and its own connect/disconnect pair
+/// rather than being bolted onto `Relay`.
+pub struct BroadcastRelay<S: ServiceCore> {
+    state: BroadcastRelayState<S::Message>,
+    overwatch_handle: OverwatchHandle,
+}
+
+impl<S: ServiceCore> Clone for BroadcastRelay<S> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            overwatch_handle: self.overwatch_handle.clone(),
+        }
+    }
+}
+
+impl<S: ServiceCore> BroadcastRelay<S>
+where
+    S::Message: Clone,
+{
+    pub fn new(overwatch_handle: OverwatchHandle) -> Self {
+        Self {
+            state: BroadcastRelayState::Disconnected,
+            overwatch_handle,
+        }
+    }
+
+    #[instrument(skip(self), err(Debug))]
+    pub async fn connect(&mut self) -> Result<(), RelayError> {
+        if let BroadcastRelayState::Disconnected = self.state {
+            let (reply, receiver) = oneshot::channel();
+            let relay_command = OverwatchCommand::SubscribeBroadcast(RelayCommand {
+                service_id: S::SERVICE_ID,
+                reply_channel: ReplyChannel(reply),
+            });
+            self.overwatch_handle.send(relay_command).await;
+            match receiver.await {
+                Ok(Ok(message)) => match message.downcast::<BroadcastOutbound<S::Message>>() {
+                    Ok(outbound) => {
+                        self.state = BroadcastRelayState::Connected(*outbound);
+                        Ok(())
+                    }
+                    Err(m) => Err(RelayError::InvalidMessage {
+                        type_id: format!("{:?}", m.type_id()),
+                        service_id: S::SERVICE_ID,
+                    }),
+                },
+                Ok(Err(e)) => Err(e),
+                Err(e) => Err(RelayError::Receiver(Box::new(e))),
+            }
+        } else {
+            Err(RelayError::AlreadyConnected)
+        }
+    }
+
+    #[instrument(skip(self), err(Debug))]
+    pub fn disconnect(&mut self) -> Result<(), RelayError> {
+        self.state = BroadcastRelayState::Disconnected;
+        Ok(())
+    }
+
+    /// Return a fresh subscriber receiving every message the service broadcasts from now on.
+    #[instrument(skip(self), err(Debug))]
+    pub fn subscribe(&self) -> Result<BroadcastInbound<S::Message>, RelayError> {
+        if let BroadcastRelayState::Connected(outbound) = &self.state {
+            Ok(outbound.subscribe())
+        } else {
+            Err(RelayError::Disconnected)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn try_send_fails_with_full_when_queue_is_saturated() {
+        let (_inbound, mut outbound) = relay::<u32>(1);
+        outbound.try_send(1).unwrap();
+        let err = outbound.try_send(2).unwrap_err();
+        assert!(matches!(err.0, RelayError::Full));
+    }
+
+    #[tokio::test]
+    async fn send_timeout_fails_with_timeout_when_capacity_never_frees_up() {
+        let (_inbound, mut outbound) = relay::<u32>(1);
+        outbound.send_blocking(1).await.unwrap();
+        let err = outbound
+            .send_timeout(2, Duration::from_millis(10))
+            .await
+            .unwrap_err();
+        assert!(matches!(err.0, RelayError::Timeout));
+    }
+
+    #[tokio::test]
+    async fn shed_newest_policy_rejects_incoming_message_when_full() {
+        let (mut inbound, mut outbound) = relay::<u32>(1);
+        outbound.set_policy(SendPolicy::ShedNewest);
+        outbound.send(1).await.unwrap();
+        let err = outbound.send(2).await.unwrap_err();
+        assert!(matches!(err.0, RelayError::Full));
+        // The queued message survives: ShedNewest drops the incoming one, not the oldest.
+        assert_eq!(inbound.recv().await, Some(1));
+    }
+
+    #[tokio::test]
+    async fn cloned_request_outbound_relays_pipeline_concurrent_requests() {
+        let (mut inbound, outbound) = request_relay::<u32, u32>(4);
+        tokio::spawn(async move {
+            for _ in 0..2 {
+                let (message, replier) = inbound.recv().await.unwrap();
+                replier.reply(message * 10).await.unwrap();
+            }
+        });
+
+        let mut first = outbound.clone();
+        let mut second = outbound.clone();
+        let (a, b) = tokio::join!(first.request(1), second.request(2));
+        let mut replies = [a.unwrap(), b.unwrap()];
+        replies.sort_unstable();
+        assert_eq!(replies, [10, 20]);
+    }
+
+    #[tokio::test]
+    async fn inbound_stats_reports_live_queue_depth() {
+        let (inbound, mut outbound) = relay::<u32>(4);
+        assert_eq!(inbound.stats().queue_depth, 0);
+        outbound.send(1).await.unwrap();
+        outbound.send(2).await.unwrap();
+        assert_eq!(inbound.stats().queue_depth, 2);
+    }
+
+    #[tokio::test]
+    async fn request_timeout_evicts_the_pending_entry_on_expiry() {
+        let (mut inbound, mut outbound) = request_relay::<u32, u32>(4);
+        let err = outbound
+            .request_timeout(1, Duration::from_millis(10))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, RelayError::Timeout));
+
+        // The request was still delivered; answering it late must not panic or deadlock now
+        // that its pending entry has been evicted.
+        let (message, replier) = inbound.recv().await.unwrap();
+        assert_eq!(message, 1);
+        replier.reply(10).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn broadcast_fans_a_message_out_to_every_subscriber() {
+        let (outbound, mut first) = broadcast_relay::<u32>(4);
+        let mut second = outbound.subscribe();
+
+        outbound.send(7).unwrap();
+
+        assert_eq!(first.recv().await.unwrap(), 7);
+        assert_eq!(second.recv().await.unwrap(), 7);
+    }
+
+    #[tokio::test]
+    async fn broadcast_recv_reports_lagged_once_a_subscriber_falls_behind() {
+        let (outbound, mut inbound) = broadcast_relay::<u32>(2);
+
+        for message in 0..4 {
+            outbound.send(message).unwrap();
+        }
+
+        let err = inbound.recv().await.unwrap_err();
+        assert!(matches!(err, RelayError::Lagged { skipped: 2 }));
+        // Having reported the gap, the subscriber resumes from where the channel now stands.
+        assert_eq!(inbound.recv().await.unwrap(), 2);
+        assert_eq!(inbound.recv().await.unwrap(), 3);
+    }
 }
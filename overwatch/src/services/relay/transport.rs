@@ -0,0 +1,354 @@
+//! Cross-process relay bridge.
+//!
+//! A plain [`super::Relay`] only connects services living inside the same `Overwatch`
+//! process, handing out an in-memory [`super::OutboundRelay`]. This module adds a second way
+//! to reach a service: dial a [`Transport`] to a remote process/host, frame `S::Message`
+//! values with a [`Codec`], and bridge them onto the wire instead of an in-process channel.
+
+use std::io;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use bytes::BytesMut;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tracing::error;
+
+use super::{InboundRelay, OutboundRelay, RelayError};
+
+/// Maximum accepted frame size; guards against a malformed length prefix causing an
+/// unbounded allocation.
+const MAX_FRAME_LEN: usize = 16 * 1024 * 1024;
+
+/// Delay applied between `accept()` failures, so a persistent error (e.g. fd exhaustion) backs
+/// off instead of spinning the accept loop on a busy CPU core.
+const ACCEPT_ERROR_BACKOFF: Duration = Duration::from_millis(100);
+
+/// A byte-oriented connection to a remote relay bridge. TCP streams, Unix domain sockets and
+/// Windows named pipes all satisfy this the same way, since they all implement tokio's
+/// `AsyncRead + AsyncWrite`.
+pub trait Connection: AsyncRead + AsyncWrite + Unpin + Send + 'static {}
+impl<T: AsyncRead + AsyncWrite + Unpin + Send + 'static> Connection for T {}
+
+/// A connector that knows how to dial the process hosting the target service.
+#[async_trait]
+pub trait Transport: Send + Sync + 'static {
+    type Connection: Connection;
+
+    /// Dial the remote endpoint, returning the established connection.
+    async fn connect(&self) -> io::Result<Self::Connection>;
+}
+
+/// TCP-backed [`Transport`].
+#[derive(Debug, Clone, Copy)]
+pub struct TcpTransport {
+    pub addr: std::net::SocketAddr,
+}
+
+#[async_trait]
+impl Transport for TcpTransport {
+    type Connection = tokio::net::TcpStream;
+
+    async fn connect(&self) -> io::Result<Self::Connection> {
+        tokio::net::TcpStream::connect(self.addr).await
+    }
+}
+
+/// Unix domain socket [`Transport`].
+#[cfg(unix)]
+#[derive(Debug, Clone)]
+pub struct UnixTransport {
+    pub path: std::path::PathBuf,
+}
+
+#[cfg(unix)]
+#[async_trait]
+impl Transport for UnixTransport {
+    type Connection = tokio::net::UnixStream;
+
+    async fn connect(&self) -> io::Result<Self::Connection> {
+        tokio::net::UnixStream::connect(&self.path).await
+    }
+}
+
+/// Windows named pipe [`Transport`].
+#[cfg(windows)]
+#[derive(Debug, Clone)]
+pub struct NamedPipeTransport {
+    pub name: String,
+}
+
+#[cfg(windows)]
+#[async_trait]
+impl Transport for NamedPipeTransport {
+    type Connection = tokio::net::windows::named_pipe::NamedPipeClient;
+
+    async fn connect(&self) -> io::Result<Self::Connection> {
+        tokio::net::windows::named_pipe::ClientOptions::new().open(&self.name)
+    }
+}
+
+/// The remote-side counterpart of [`Transport`]: accepts incoming [`Connection`]s from callers
+/// dialing in with [`Relay::connect_remote`](super::Relay::connect_remote).
+#[async_trait]
+pub trait Listener: Send + 'static {
+    type Connection: Connection;
+
+    /// Accept the next incoming connection.
+    async fn accept(&mut self) -> io::Result<Self::Connection>;
+}
+
+/// TCP-backed [`Listener`].
+pub struct TcpListener(pub tokio::net::TcpListener);
+
+#[async_trait]
+impl Listener for TcpListener {
+    type Connection = tokio::net::TcpStream;
+
+    async fn accept(&mut self) -> io::Result<Self::Connection> {
+        let (connection, _addr) = self.0.accept().await?;
+        Ok(connection)
+    }
+}
+
+/// Unix domain socket [`Listener`].
+#[cfg(unix)]
+pub struct UnixListener(pub tokio::net::UnixListener);
+
+#[cfg(unix)]
+#[async_trait]
+impl Listener for UnixListener {
+    type Connection = tokio::net::UnixStream;
+
+    async fn accept(&mut self) -> io::Result<Self::Connection> {
+        let (connection, _addr) = self.0.accept().await?;
+        Ok(connection)
+    }
+}
+
+/// Windows named pipe [`Listener`]. A new pipe instance is created for every accepted
+/// connection so the server keeps listening for the next one.
+#[cfg(windows)]
+pub struct NamedPipeListener {
+    pub name: String,
+    server: tokio::net::windows::named_pipe::NamedPipeServer,
+}
+
+#[cfg(windows)]
+#[async_trait]
+impl Listener for NamedPipeListener {
+    type Connection = tokio::net::windows::named_pipe::NamedPipeServer;
+
+    async fn accept(&mut self) -> io::Result<Self::Connection> {
+        self.server.connect().await?;
+        let next = tokio::net::windows::named_pipe::ServerOptions::new().create(&self.name)?;
+        Ok(std::mem::replace(&mut self.server, next))
+    }
+}
+
+/// Encodes a message into a wire frame and decodes it back on the other side.
+pub trait Codec<M>: Clone + Send + Sync + 'static {
+    fn encode(&self, message: &M) -> io::Result<Vec<u8>>;
+    fn decode(&self, frame: &[u8]) -> io::Result<M>;
+}
+
+/// Default [`Codec`], packing messages with `bincode` for a compact binary encoding.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BincodeCodec;
+
+impl<M: Serialize + DeserializeOwned + Send + Sync + 'static> Codec<M> for BincodeCodec {
+    fn encode(&self, message: &M) -> io::Result<Vec<u8>> {
+        bincode::serialize(message).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    fn decode(&self, frame: &[u8]) -> io::Result<M> {
+        bincode::deserialize(frame).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
+
+/// Read one length-delimited frame, buffering partial reads until it is complete.
+/// Returns `Ok(None)` on a clean EOF between frames.
+async fn read_frame<C: AsyncRead + Unpin>(connection: &mut C) -> io::Result<Option<BytesMut>> {
+    let mut length_buf = [0u8; 4];
+    match connection.read_exact(&mut length_buf).await {
+        Ok(_) => {}
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e),
+    }
+    let length = u32::from_be_bytes(length_buf) as usize;
+    if length > MAX_FRAME_LEN {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "frame too large"));
+    }
+    let mut frame = BytesMut::zeroed(length);
+    connection.read_exact(&mut frame).await?;
+    Ok(Some(frame))
+}
+
+async fn write_frame<C: AsyncWrite + Unpin>(connection: &mut C, payload: &[u8]) -> io::Result<()> {
+    connection.write_u32(payload.len() as u32).await?;
+    connection.write_all(payload).await?;
+    Ok(())
+}
+
+/// Drive the local side of a cross-process relay bridge: read `S::Message` values off
+/// `inbound`, encode and frame each one onto `connection`. Returns once the relay or the
+/// connection closes.
+pub(super) async fn bridge_outbound<M, C, Co>(
+    mut inbound: InboundRelay<M>,
+    mut connection: C,
+    codec: Co,
+) -> Result<(), RelayError>
+where
+    M: Send + 'static,
+    C: AsyncWrite + Unpin,
+    Co: Codec<M>,
+{
+    while let Some(message) = inbound.recv().await {
+        let frame = codec.encode(&message).map_err(|_| RelayError::Disconnected)?;
+        write_frame(&mut connection, &frame)
+            .await
+            .map_err(|_| RelayError::Disconnected)?;
+    }
+    Ok(())
+}
+
+/// The remote-side counterpart of [`bridge_outbound`]: deframe and decode values off
+/// `connection` and re-inject each one into the target service's relay via `outbound`. EOF or
+/// a deframe/decode failure both map to [`RelayError::Disconnected`].
+pub(super) async fn bridge_inbound<M, C, Co>(
+    mut connection: C,
+    mut outbound: OutboundRelay<M>,
+    codec: Co,
+) -> Result<(), RelayError>
+where
+    M: Send + 'static,
+    C: AsyncRead + Unpin,
+    Co: Codec<M>,
+{
+    loop {
+        let frame = read_frame(&mut connection)
+            .await
+            .map_err(|_| RelayError::Disconnected)?;
+        let Some(frame) = frame else {
+            return Err(RelayError::Disconnected);
+        };
+        let message = codec.decode(&frame).map_err(|_| RelayError::Disconnected)?;
+        outbound
+            .send(message)
+            .await
+            .map_err(|_| RelayError::Disconnected)?;
+    }
+}
+
+/// Accept connections off `listener` forever, bridging each one onto `outbound` via
+/// [`bridge_inbound`] in its own task so multiple remote callers can be served concurrently.
+/// This is the accept-loop run on the process hosting the target service, the counterpart of
+/// [`Relay::connect_remote`](super::Relay::connect_remote) on the caller's side.
+pub(super) async fn serve<L, M, Co>(mut listener: L, outbound: OutboundRelay<M>, codec: Co)
+where
+    L: Listener,
+    M: Send + 'static,
+    Co: Codec<M>,
+{
+    loop {
+        let connection = match listener.accept().await {
+            Ok(connection) => connection,
+            Err(error) => {
+                error!(%error, "failed to accept a connection, retrying after a backoff");
+                tokio::time::sleep(ACCEPT_ERROR_BACKOFF).await;
+                continue;
+            }
+        };
+        tokio::spawn(bridge_inbound(
+            connection,
+            outbound.clone(),
+            codec.clone(),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+    struct Ping(u32);
+
+    /// [`Listener`] that fails `failures_left` times before handing out its one connection, to
+    /// exercise [`serve`]'s backoff-and-retry path.
+    struct FlakyListener {
+        failures_left: usize,
+        connection: Option<tokio::io::DuplexStream>,
+    }
+
+    #[async_trait]
+    impl Listener for FlakyListener {
+        type Connection = tokio::io::DuplexStream;
+
+        async fn accept(&mut self) -> io::Result<Self::Connection> {
+            if self.failures_left > 0 {
+                self.failures_left -= 1;
+                return Err(io::Error::other("simulated accept failure"));
+            }
+            Ok(self.connection.take().expect("only one connection configured"))
+        }
+    }
+
+    #[tokio::test]
+    async fn read_frame_buffers_partial_reads() {
+        let (mut client, mut server) = tokio::io::duplex(4);
+        let payload = b"hello frame";
+        let write = tokio::spawn(async move {
+            write_frame(&mut client, payload).await.unwrap();
+        });
+        let frame = read_frame(&mut server).await.unwrap().unwrap();
+        assert_eq!(&frame[..], payload);
+        write.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn read_frame_reports_clean_eof_as_none() {
+        let (client, mut server) = tokio::io::duplex(4);
+        drop(client);
+        assert!(read_frame(&mut server).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn bridge_outbound_then_inbound_round_trips_a_message() {
+        // Caller side: local relay whose inbound half is bridged onto `client`.
+        let (caller_inbound, mut caller_outbound) = super::super::relay::<Ping>(4);
+        // Service side: local relay whose outbound half is fed by `bridge_inbound`.
+        let (mut service_inbound, service_outbound) = super::super::relay::<Ping>(4);
+
+        let (client, server) = tokio::io::duplex(64);
+        tokio::spawn(bridge_outbound(caller_inbound, client, BincodeCodec));
+        tokio::spawn(bridge_inbound(server, service_outbound, BincodeCodec));
+
+        caller_outbound.send(Ping(7)).await.unwrap();
+        let received = service_inbound.recv().await.unwrap();
+        assert_eq!(received, Ping(7));
+    }
+
+    #[tokio::test]
+    async fn serve_backs_off_and_recovers_after_accept_failures() {
+        let (client, server_conn) = tokio::io::duplex(64);
+        let listener = FlakyListener {
+            failures_left: 2,
+            connection: Some(server_conn),
+        };
+
+        let (caller_inbound, mut caller_outbound) = super::super::relay::<Ping>(4);
+        let (mut service_inbound, service_outbound) = super::super::relay::<Ping>(4);
+
+        tokio::spawn(serve(listener, service_outbound, BincodeCodec));
+        tokio::spawn(bridge_outbound(caller_inbound, client, BincodeCodec));
+
+        caller_outbound.send(Ping(9)).await.unwrap();
+        let received = tokio::time::timeout(Duration::from_secs(2), service_inbound.recv())
+            .await
+            .expect("serve should retry past the failures and still accept the connection")
+            .unwrap();
+        assert_eq!(received, Ping(9));
+    }
+}